@@ -1,17 +1,32 @@
 use axum::{
     response::{Html, IntoResponse},
     routing::get,
-    Router,
+    Json, Router,
 };
 use askama::Template;
 use chrono::{DateTime, Utc};
 use clap::Parser;
-use std::sync::Arc;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::BufReader;
+use std::sync::{Arc, Mutex};
+use tokio::sync::RwLock;
 use tokio::time::timeout;
 use std::time::Duration;
-use rustls::pki_types::ServerName;
+use rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
+use rustls::client::{verify_server_cert_signed_by_trust_anchor, ParsedCertificate, WebPkiServerVerifier};
+use rustls::pki_types::{CertificateDer, ServerName, UnixTime};
+use rustls::{DigitallySignedStruct, SignatureScheme};
+use axum::extract::Request;
+use hyper::body::Incoming;
+use hyper::service::service_fn;
+use hyper_util::rt::{TokioExecutor, TokioIo};
 use tokio::net::TcpStream;
 use tokio_rustls::TlsConnector;
+use tokio_stream::StreamExt;
+use tls_listener::TlsListener;
+use tower::Service;
 use x509_parser::prelude::*;
 
 #[derive(Parser, Debug)]
@@ -19,9 +34,34 @@ use x509_parser::prelude::*;
 struct Args {
     #[arg(short, long, default_value = "urls.txt")]
     urls: String,
-    
+
     #[arg(short, long, default_value = "3000")]
     port: u16,
+
+    /// PEM file containing the certificate chain to serve the dashboard over HTTPS
+    #[arg(long, requires = "tls_key")]
+    tls_cert: Option<String>,
+
+    /// PEM file containing the private key matching --tls-cert
+    #[arg(long, requires = "tls_cert")]
+    tls_key: Option<String>,
+
+    /// Seconds between background certificate checks
+    #[arg(long, default_value = "3600")]
+    interval: u64,
+
+    /// Days before expiry at which a certificate is flagged as expiring soon
+    #[arg(long, default_value = "30")]
+    warn_days: i64,
+
+    /// Days before expiry at which a certificate is flagged as critical
+    #[arg(long, default_value = "7")]
+    critical_days: i64,
+
+    /// Webhook URL to POST a JSON alert to when a domain first crosses the
+    /// warning or critical threshold
+    #[arg(long)]
+    webhook_url: Option<String>,
 }
 
 #[derive(Template)]
@@ -44,31 +84,254 @@ impl IntoResponse for DashboardTemplate {
     }
 }
 
+#[derive(Serialize, Clone)]
 struct SiteResult {
     domain: String,
     status: String,
     expiry: String,
     days_left: i64,
+    detail: Option<String>,
+    #[serde(flatten)]
+    metadata: CertMetadata,
+}
+
+// Leaf certificate fields parsed by x509-parser, plus the length of the
+// chain the server presented.
+#[derive(Debug, Clone, Default, Serialize)]
+struct CertMetadata {
+    issuer_cn: Option<String>,
+    issuer_o: Option<String>,
+    subject: String,
+    sans: Vec<String>,
+    serial_number: String,
+    signature_algorithm: String,
+    not_before: String,
+    chain_length: usize,
+}
+
+// Replaces the old catch-all "ERROR" status with a specific failure mode.
+#[derive(Debug, Clone)]
+enum CheckOutcome {
+    Valid(DateTime<Utc>),
+    ExpiringSoon(DateTime<Utc>),
+    CriticalExpiry(DateTime<Utc>),
+    Expired(DateTime<Utc>),
+    SelfSigned(DateTime<Utc>),
+    HostnameMismatch(DateTime<Utc>),
+    UntrustedRoot(DateTime<Utc>, String),
+    Unreachable(String),
+}
+
+// How urgently a check result deserves a webhook alert.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AlertLevel {
+    None,
+    Warning,
+    Critical,
+}
+
+impl CheckOutcome {
+    fn label(&self) -> &'static str {
+        match self {
+            CheckOutcome::Valid(_) => "VALID",
+            CheckOutcome::ExpiringSoon(_) => "EXPIRING_SOON",
+            CheckOutcome::CriticalExpiry(_) => "CRITICAL",
+            CheckOutcome::Expired(_) => "EXPIRED",
+            CheckOutcome::SelfSigned(_) => "SELF_SIGNED",
+            CheckOutcome::HostnameMismatch(_) => "HOSTNAME_MISMATCH",
+            CheckOutcome::UntrustedRoot(..) => "UNTRUSTED_ROOT",
+            CheckOutcome::Unreachable(_) => "UNREACHABLE",
+        }
+    }
+
+    fn expiry(&self) -> Option<DateTime<Utc>> {
+        match self {
+            CheckOutcome::Valid(e)
+            | CheckOutcome::ExpiringSoon(e)
+            | CheckOutcome::CriticalExpiry(e)
+            | CheckOutcome::Expired(e)
+            | CheckOutcome::SelfSigned(e)
+            | CheckOutcome::HostnameMismatch(e)
+            | CheckOutcome::UntrustedRoot(e, _) => Some(*e),
+            CheckOutcome::Unreachable(_) => None,
+        }
+    }
+
+    fn days_left(&self) -> i64 {
+        match self.expiry() {
+            Some(expiry) => expiry.signed_duration_since(Utc::now()).num_days(),
+            None => 9999,
+        }
+    }
+
+    fn detail(&self) -> Option<String> {
+        match self {
+            CheckOutcome::Unreachable(reason) | CheckOutcome::UntrustedRoot(_, reason) => {
+                Some(reason.clone())
+            }
+            _ => None,
+        }
+    }
+}
+
+// Records the webpki chain-of-trust error, if any, so the handshake can
+// still complete and we can inspect the leaf certificate ourselves.
+#[derive(Default, Debug)]
+struct VerificationState {
+    chain_error: Option<String>,
+}
+
+#[derive(Debug)]
+struct RecordingVerifier {
+    roots: Arc<rustls::RootCertStore>,
+    inner: Arc<WebPkiServerVerifier>,
+    state: Arc<Mutex<VerificationState>>,
+}
+
+impl ServerCertVerifier for RecordingVerifier {
+    fn verify_server_cert(
+        &self,
+        end_entity: &CertificateDer<'_>,
+        intermediates: &[CertificateDer<'_>],
+        _server_name: &ServerName<'_>,
+        _ocsp_response: &[u8],
+        now: UnixTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        // `inner.verify_server_cert` fails on either a broken chain OR a
+        // hostname mismatch in one combined call, so a trusted chain with a
+        // CN-only match (no SAN) would be wrongly reported as untrusted.
+        // Check chain-of-trust on its own, independent of hostname matching.
+        let chain_result = verify_server_cert_signed_by_trust_anchor(
+            &ParsedCertificate::try_from(end_entity)?,
+            &self.roots,
+            intermediates,
+            now,
+            rustls::crypto::ring::default_provider().signature_verification_algorithms,
+        );
+
+        if let Err(err) = chain_result {
+            self.state.lock().unwrap().chain_error = Some(err.to_string());
+        }
+
+        // Accept unconditionally so the handshake completes and we can still
+        // inspect the leaf certificate to classify the failure ourselves.
+        Ok(ServerCertVerified::assertion())
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        self.inner.verify_tls12_signature(message, cert, dss)
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        self.inner.verify_tls13_signature(message, cert, dss)
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.inner.supported_verify_schemes()
+    }
 }
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let args = Arc::new(Args::parse());
     let addr = format!("127.0.0.1:{}", args.port);
+    let cache: Arc<RwLock<Vec<SiteResult>>> = Arc::new(RwLock::new(Vec::new()));
+    let webhook_client = reqwest::Client::new();
 
-    let app = Router::new().route("/", get(move || handler(args.clone())));
+    tokio::spawn(run_periodic_checks(args.clone(), cache.clone(), webhook_client));
+
+    let app = Router::new()
+        .route("/", get({
+            let cache = cache.clone();
+            move || handler(cache.clone())
+        }))
+        .route("/api/checks", get({
+            let cache = cache.clone();
+            move || api_checks(cache.clone())
+        }));
 
     let listener = tokio::net::TcpListener::bind(&addr).await?;
-    println!("🚀 SSL Dashboard live at http://{}", addr);
-    
-    axum::serve(listener, app).await?;
+
+    match (&args.tls_cert, &args.tls_key) {
+        (Some(cert_path), Some(key_path)) => {
+            let tls_acceptor = load_tls_acceptor(cert_path, key_path)?;
+            println!("🔒 SSL Dashboard live at https://{}", addr);
+            serve_tls(listener, tls_acceptor, app).await;
+        }
+        (None, None) => {
+            println!("🚀 SSL Dashboard live at http://{}", addr);
+            axum::serve(listener, app).await?;
+        }
+        _ => unreachable!("clap enforces --tls-cert and --tls-key together"),
+    }
 
     Ok(())
 }
 
-async fn handler(args: Arc<Args>) -> impl IntoResponse {
-    let mut sites = run_checks(&args.urls).await;
-    
+// axum::serve only accepts a plain tokio::net::TcpListener, so TLS connections
+// from tls-listener are served with a manual hyper accept loop instead.
+async fn serve_tls(listener: tokio::net::TcpListener, tls_acceptor: tokio_rustls::TlsAcceptor, app: Router) {
+    let mut tls_listener = TlsListener::new(tls_acceptor, listener);
+
+    while let Some(conn) = tls_listener.next().await {
+        let stream = match conn {
+            Ok(stream) => stream,
+            Err(err) => {
+                eprintln!("TLS accept error: {err}");
+                continue;
+            }
+        };
+
+        let tower_service = app.clone();
+        tokio::spawn(async move {
+            let io = TokioIo::new(stream);
+            let hyper_service = service_fn(move |request: Request<Incoming>| {
+                tower_service.clone().call(request)
+            });
+
+            if let Err(err) = hyper_util::server::conn::auto::Builder::new(TokioExecutor::new())
+                .serve_connection_with_upgrades(io, hyper_service)
+                .await
+            {
+                eprintln!("connection error: {err}");
+            }
+        });
+    }
+}
+
+/// Loads a PEM certificate chain and private key into a `TlsAcceptor` used to
+/// upgrade each accepted `TcpStream` before it reaches the axum router.
+fn load_tls_acceptor(
+    cert_path: &str,
+    key_path: &str,
+) -> Result<tokio_rustls::TlsAcceptor, Box<dyn std::error::Error>> {
+    let cert_file = &mut BufReader::new(File::open(cert_path)?);
+    let certs = rustls_pemfile::certs(cert_file).collect::<Result<Vec<_>, _>>()?;
+
+    let key_file = &mut BufReader::new(File::open(key_path)?);
+    let key = rustls_pemfile::private_key(key_file)?
+        .ok_or("no private key found in --tls-key file")?;
+
+    let config = rustls::ServerConfig::builder()
+        .with_no_client_auth()
+        .with_single_cert(certs, key)?;
+
+    Ok(tokio_rustls::TlsAcceptor::from(Arc::new(config)))
+}
+
+async fn handler(cache: Arc<RwLock<Vec<SiteResult>>>) -> impl IntoResponse {
+    let mut sites = cache.read().await.clone();
+
     // trying to sort by expiry date
     sites.sort_by_key(|s| s.days_left);
 
@@ -76,33 +339,132 @@ async fn handler(args: Arc<Args>) -> impl IntoResponse {
     DashboardTemplate { sites, last_updated }
 }
 
-async fn run_checks(file_path: &str) -> Vec<SiteResult> {
+async fn api_checks(cache: Arc<RwLock<Vec<SiteResult>>>) -> impl IntoResponse {
+    Json(cache.read().await.clone())
+}
+
+// Re-runs run_checks on --interval seconds, caching results for the HTTP
+// handlers and firing webhook alerts on threshold crossings.
+async fn run_periodic_checks(
+    args: Arc<Args>,
+    cache: Arc<RwLock<Vec<SiteResult>>>,
+    webhook_client: reqwest::Client,
+) {
+    let mut last_alert: HashMap<String, AlertLevel> = HashMap::new();
+
+    loop {
+        let sites = run_checks(&args.urls, args.warn_days, args.critical_days).await;
+
+        for site in &sites {
+            notify_on_threshold_crossing(&args, &webhook_client, &mut last_alert, site).await;
+        }
+
+        *cache.write().await = sites;
+        tokio::time::sleep(Duration::from_secs(args.interval)).await;
+    }
+}
+
+// POSTs a JSON alert to --webhook-url the first time a site crosses into a
+// given AlertLevel; last_alert tracks that so each transition fires once.
+async fn notify_on_threshold_crossing(
+    args: &Args,
+    webhook_client: &reqwest::Client,
+    last_alert: &mut HashMap<String, AlertLevel>,
+    site: &SiteResult,
+) {
+    let Some(webhook_url) = &args.webhook_url else { return };
+
+    let level = alert_level_for_status(&site.status);
+    let previous = last_alert.get(&site.domain).copied().unwrap_or(AlertLevel::None);
+    if level == previous {
+        return;
+    }
+    last_alert.insert(site.domain.clone(), level);
+
+    if level == AlertLevel::None {
+        return;
+    }
+
+    let payload = serde_json::json!({
+        "domain": site.domain,
+        "days_left": site.days_left,
+        "expiry": site.expiry,
+        "status": site.status,
+    });
+
+    if let Err(err) = webhook_client.post(webhook_url).json(&payload).send().await {
+        eprintln!("webhook POST to {webhook_url} failed: {err}");
+    }
+}
+
+fn alert_level_for_status(status: &str) -> AlertLevel {
+    match status {
+        // A cert we can't reach or can't trust is at least as actionable as
+        // one that's about to expire, so these all page too.
+        "CRITICAL" | "EXPIRED" | "SELF_SIGNED" | "HOSTNAME_MISMATCH" | "UNTRUSTED_ROOT" | "UNREACHABLE" => {
+            AlertLevel::Critical
+        }
+        "EXPIRING_SOON" => AlertLevel::Warning,
+        _ => AlertLevel::None,
+    }
+}
+
+// A host/port pair parsed from one urls.txt line, e.g. "mail.example.com:465"
+// or "gemini://example.com:1965". Defaults to port 443 when omitted.
+struct Target {
+    host: String,
+    port: u16,
+}
+
+fn parse_target(line: &str) -> Option<Target> {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with('#') {
+        return None;
+    }
+
+    let without_scheme = line.split_once("://").map_or(line, |(_, rest)| rest);
+
+    let (host, port) = match without_scheme.rsplit_once(':') {
+        Some((host, port_str)) => match port_str.parse::<u16>() {
+            Ok(port) => (host.to_string(), port),
+            Err(_) => (host.to_string(), 443),
+        },
+        None => (without_scheme.to_string(), 443),
+    };
+
+    Some(Target { host, port })
+}
+
+async fn run_checks(file_path: &str, warn_days: i64, critical_days: i64) -> Vec<SiteResult> {
     let mut results = Vec::new();
     let contents = std::fs::read_to_string(file_path).unwrap_or_default();
     let mut handles = vec![];
 
-    for domain in contents.lines() {
-        let domain = domain.trim().to_string();
-        if domain.is_empty() || domain.starts_with('#') { continue; }
+    for line in contents.lines() {
+        let Some(target) = parse_target(line) else { continue };
+        let display = line.trim().to_string();
 
         handles.push(tokio::spawn(async move {
-            match timeout(Duration::from_secs(5), check_ssl_expiry(&domain)).await {
-                Ok(Ok(expiry)) => {
-                    let now = Utc::now();
-                    let days = expiry.signed_duration_since(now).num_days();
-                    SiteResult {
-                        domain,
-                        status: if days < 7 { "EXPIRED".into() } else { "VALID".into() },
-                        expiry: expiry.format("%Y-%m-%d").to_string(),
-                        days_left: days,
-                    }
-                }
-                _ => SiteResult {
-                    domain,
-                    status: "ERROR".into(),
-                    expiry: "N/A".into(),
-                    days_left: 9999,
-                },
+            let (outcome, metadata) = match timeout(
+                Duration::from_secs(5),
+                check_ssl_expiry(&target.host, target.port, warn_days, critical_days),
+            )
+            .await
+            {
+                Ok(result) => result,
+                Err(_) => (CheckOutcome::Unreachable("timed out".into()), CertMetadata::default()),
+            };
+
+            SiteResult {
+                domain: display,
+                status: outcome.label().to_string(),
+                expiry: outcome
+                    .expiry()
+                    .map(|e| e.format("%Y-%m-%d").to_string())
+                    .unwrap_or_else(|| "N/A".into()),
+                days_left: outcome.days_left(),
+                detail: outcome.detail(),
+                metadata,
             }
         }));
     }
@@ -113,27 +475,255 @@ async fn run_checks(file_path: &str) -> Vec<SiteResult> {
     results
 }
 
-async fn check_ssl_expiry(domain: &str) -> Result<DateTime<Utc>, Box<dyn std::error::Error + Send + Sync>> {
+async fn check_ssl_expiry(
+    host: &str,
+    port: u16,
+    warn_days: i64,
+    critical_days: i64,
+) -> (CheckOutcome, CertMetadata) {
+    match try_check_ssl_expiry(host, port, warn_days, critical_days).await {
+        Ok(result) => result,
+        Err(err) => (CheckOutcome::Unreachable(err.to_string()), CertMetadata::default()),
+    }
+}
+
+async fn try_check_ssl_expiry(
+    host: &str,
+    port: u16,
+    warn_days: i64,
+    critical_days: i64,
+) -> Result<(CheckOutcome, CertMetadata), Box<dyn std::error::Error + Send + Sync>> {
     let mut root_store = rustls::RootCertStore::empty();
     root_store.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned());
-    
+    let root_store = Arc::new(root_store);
+
+    let state = Arc::new(Mutex::new(VerificationState::default()));
+    let verifier = RecordingVerifier {
+        roots: root_store.clone(),
+        inner: WebPkiServerVerifier::builder(root_store).build()?,
+        state: state.clone(),
+    };
+
     let config = rustls::ClientConfig::builder()
-        .with_root_certificates(root_store)
+        .dangerous()
+        .with_custom_certificate_verifier(Arc::new(verifier))
         .with_no_client_auth();
-    
+
     let connector = TlsConnector::from(Arc::new(config));
-    let server_name = ServerName::try_from(domain.to_string())?.to_owned();
+    let server_name = ServerName::try_from(host.to_string())?.to_owned();
 
-    let addr = format!("{}:443", domain);
+    let addr = format!("{}:{}", host, port);
     let stream = TcpStream::connect(addr).await?;
     let tls_stream = connector.connect(server_name, stream).await?;
-    
+
     let (_, session) = tls_stream.get_ref();
     let cert_chain = session.peer_certificates().ok_or("No certificate found")?;
-    
+
     let cert_der = &cert_chain[0];
     let (_, parsed_cert) = parse_x509_certificate(cert_der)?;
-    
-    let expiry_ts = parsed_cert.validity().not_after.timestamp();
-    Ok(DateTime::from_timestamp(expiry_ts, 0).unwrap_or_default())
+
+    let validity = parsed_cert.validity();
+    let not_after = DateTime::from_timestamp(validity.not_after.timestamp(), 0).unwrap_or_default();
+    let now = Utc::now();
+
+    let self_signed = parsed_cert.issuer() == parsed_cert.subject();
+    let hostname_matches = parsed_cert
+        .subject_alternative_name()
+        .ok()
+        .flatten()
+        .map(|san| {
+            san.value.general_names.iter().any(|name| match name {
+                GeneralName::DNSName(dns) => dns_name_matches(dns, host),
+                _ => false,
+            })
+        })
+        .unwrap_or(false)
+        || parsed_cert
+            .subject()
+            .iter_common_name()
+            .any(|cn| cn.as_str().map(|s| dns_name_matches(s, host)).unwrap_or(false));
+
+    let outcome = classify_outcome(
+        self_signed,
+        hostname_matches,
+        state.lock().unwrap().chain_error.clone(),
+        now,
+        not_after,
+        warn_days,
+        critical_days,
+    );
+
+    let sans = parsed_cert
+        .subject_alternative_name()
+        .ok()
+        .flatten()
+        .map(|san| {
+            san.value
+                .general_names
+                .iter()
+                .filter_map(|name| match name {
+                    GeneralName::DNSName(dns) => Some(dns.to_string()),
+                    _ => None,
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let metadata = CertMetadata {
+        issuer_cn: parsed_cert
+            .issuer()
+            .iter_common_name()
+            .next()
+            .and_then(|cn| cn.as_str().ok())
+            .map(str::to_string),
+        issuer_o: parsed_cert
+            .issuer()
+            .iter_organization()
+            .next()
+            .and_then(|o| o.as_str().ok())
+            .map(str::to_string),
+        subject: parsed_cert.subject().to_string(),
+        sans,
+        serial_number: parsed_cert.raw_serial_as_string(),
+        signature_algorithm: parsed_cert.signature_algorithm.algorithm.to_string(),
+        not_before: DateTime::from_timestamp(validity.not_before.timestamp(), 0)
+            .unwrap_or_default()
+            .format("%Y-%m-%d")
+            .to_string(),
+        chain_length: cert_chain.len(),
+    };
+
+    Ok((outcome, metadata))
+}
+
+// Matches a SAN/CN entry against the requested host, allowing a single
+// leading "*." wildcard label as most CDN/SaaS certificates use.
+fn dns_name_matches(entry: &str, host: &str) -> bool {
+    match entry.strip_prefix("*.") {
+        Some(suffix) => host
+            .split_once('.')
+            .map(|(_, rest)| rest.eq_ignore_ascii_case(suffix))
+            .unwrap_or(false),
+        None => entry.eq_ignore_ascii_case(host),
+    }
+}
+
+fn classify_outcome(
+    self_signed: bool,
+    hostname_matches: bool,
+    chain_error: Option<String>,
+    now: DateTime<Utc>,
+    not_after: DateTime<Utc>,
+    warn_days: i64,
+    critical_days: i64,
+) -> CheckOutcome {
+    if now >= not_after {
+        CheckOutcome::Expired(not_after)
+    } else if self_signed {
+        CheckOutcome::SelfSigned(not_after)
+    } else if !hostname_matches {
+        CheckOutcome::HostnameMismatch(not_after)
+    } else if let Some(reason) = chain_error {
+        CheckOutcome::UntrustedRoot(not_after, reason)
+    } else {
+        let days = not_after.signed_duration_since(now).num_days();
+        if days < critical_days {
+            CheckOutcome::CriticalExpiry(not_after)
+        } else if days < warn_days {
+            CheckOutcome::ExpiringSoon(not_after)
+        } else {
+            CheckOutcome::Valid(not_after)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_target_defaults_to_443() {
+        let target = parse_target("example.com").unwrap();
+        assert_eq!(target.host, "example.com");
+        assert_eq!(target.port, 443);
+    }
+
+    #[test]
+    fn parse_target_reads_port_and_scheme() {
+        let target = parse_target("gemini://example.com:1965").unwrap();
+        assert_eq!(target.host, "example.com");
+        assert_eq!(target.port, 1965);
+    }
+
+    #[test]
+    fn parse_target_falls_back_to_host_on_bad_port() {
+        let target = parse_target("example.com:99999").unwrap();
+        assert_eq!(target.host, "example.com");
+        assert_eq!(target.port, 443);
+    }
+
+    #[test]
+    fn dns_name_matches_wildcard_san() {
+        assert!(dns_name_matches("*.example.com", "cdn.example.com"));
+        assert!(!dns_name_matches("*.example.com", "example.com"));
+        assert!(!dns_name_matches("*.example.com", "cdn.other.com"));
+    }
+
+    #[test]
+    fn dns_name_matches_exact() {
+        assert!(dns_name_matches("example.com", "example.com"));
+        assert!(dns_name_matches("Example.com", "example.com"));
+        assert!(!dns_name_matches("example.com", "other.com"));
+    }
+
+    #[test]
+    fn classify_outcome_picks_critical_before_warning() {
+        let now = DateTime::from_timestamp(0, 0).unwrap();
+        let not_after = DateTime::from_timestamp(5 * 86_400, 0).unwrap();
+        let outcome = classify_outcome(false, true, None, now, not_after, 30, 7);
+        assert!(matches!(outcome, CheckOutcome::CriticalExpiry(_)));
+    }
+
+    #[test]
+    fn classify_outcome_prioritizes_hostname_mismatch_over_trust() {
+        let now = DateTime::from_timestamp(0, 0).unwrap();
+        let not_after = DateTime::from_timestamp(365 * 86_400, 0).unwrap();
+        let outcome = classify_outcome(
+            false,
+            false,
+            Some("unknown issuer".to_string()),
+            now,
+            not_after,
+            30,
+            7,
+        );
+        assert!(matches!(outcome, CheckOutcome::HostnameMismatch(_)));
+    }
+
+    #[test]
+    fn classify_outcome_trusts_cn_only_cert_with_no_san() {
+        // A cert with no SAN relies on this code's manual CN fallback to set
+        // hostname_matches = true; the chain itself is independently trusted,
+        // so this must resolve to Valid rather than UntrustedRoot.
+        let now = DateTime::from_timestamp(0, 0).unwrap();
+        let not_after = DateTime::from_timestamp(365 * 86_400, 0).unwrap();
+        let outcome = classify_outcome(false, true, None, now, not_after, 30, 7);
+        assert!(matches!(outcome, CheckOutcome::Valid(_)));
+    }
+
+    #[test]
+    fn alert_level_for_status_maps_critical_and_warning() {
+        assert_eq!(alert_level_for_status("EXPIRED"), AlertLevel::Critical);
+        assert_eq!(alert_level_for_status("CRITICAL"), AlertLevel::Critical);
+        assert_eq!(alert_level_for_status("EXPIRING_SOON"), AlertLevel::Warning);
+        assert_eq!(alert_level_for_status("VALID"), AlertLevel::None);
+    }
+
+    #[test]
+    fn alert_level_for_status_maps_unreachable_and_untrusted_variants() {
+        assert_eq!(alert_level_for_status("SELF_SIGNED"), AlertLevel::Critical);
+        assert_eq!(alert_level_for_status("HOSTNAME_MISMATCH"), AlertLevel::Critical);
+        assert_eq!(alert_level_for_status("UNTRUSTED_ROOT"), AlertLevel::Critical);
+        assert_eq!(alert_level_for_status("UNREACHABLE"), AlertLevel::Critical);
+    }
 }